@@ -0,0 +1,211 @@
+use crate::color::RGB;
+
+/// The maximum line length, in characters, of a PPM file produced by
+/// [`Canvas::to_ppm`].
+const MAX_PPM_LINE_LENGTH: usize = 70;
+
+/// A grid of pixels that can be written to and exported as an image.
+#[derive(Debug, PartialEq)]
+pub struct Canvas {
+    /// the width of the canvas, in pixels
+    width: usize,
+    /// the height of the canvas, in pixels
+    height: usize,
+    /// the pixels, in row-major order
+    pixels: Vec<RGB>,
+}
+
+impl Canvas {
+    /// Returns a new canvas, with every pixel black.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` the width of the canvas, in pixels
+    /// * `height` the height of the canvas, in pixels
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::canvas::Canvas;
+    /// let canvas: Canvas = Canvas::new(10, 20);
+    /// ```
+    pub fn new(width: usize, height: usize) -> Canvas {
+        Canvas {
+            width,
+            height,
+            pixels: vec![RGB::new(0.0, 0.0, 0.0); width * height],
+        }
+    }
+
+    /// Sets the pixel at `(x, y)` to `color`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` the column of the pixel
+    /// * `y` the row of the pixel
+    /// * `color` the color to write
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::canvas::Canvas;
+    /// # use noray::color::RGB;
+    /// let mut canvas: Canvas = Canvas::new(10, 20);
+    /// canvas.write_pixel(2, 3, RGB::new(1.0, 0.0, 0.0));
+    /// ```
+    pub fn write_pixel(&mut self, x: usize, y: usize, color: RGB) {
+        let index = y * self.width + x;
+        self.pixels[index] = color;
+    }
+
+    /// Returns the color of the pixel at `(x, y)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` the column of the pixel
+    /// * `y` the row of the pixel
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::canvas::Canvas;
+    /// let canvas: Canvas = Canvas::new(10, 20);
+    /// let color = canvas.pixel_at(2, 3);
+    /// ```
+    pub fn pixel_at(&self, x: usize, y: usize) -> RGB {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Returns this canvas rendered as a plain PPM (P3) image, with a
+    /// trailing newline and no output line longer than 70 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::canvas::Canvas;
+    /// let canvas: Canvas = Canvas::new(5, 3);
+    /// let ppm: String = canvas.to_ppm();
+    /// ```
+    pub fn to_ppm(&self) -> String {
+        let mut ppm = format!("P3\n{} {}\n255\n", self.width, self.height);
+        for row in self.pixels.chunks(self.width) {
+            let components = row
+                .iter()
+                .flat_map(|pixel| {
+                    let (r, g, b) = pixel.to_bytes();
+                    [r, g, b]
+                })
+                .map(|component| component.to_string());
+            ppm.push_str(&wrap_line(components, MAX_PPM_LINE_LENGTH));
+            ppm.push('\n');
+        }
+        ppm
+    }
+}
+
+/// Joins `words` with spaces, breaking onto a new line before it would
+/// exceed `max_length` characters.
+fn wrap_line(words: impl Iterator<Item = String>, max_length: usize) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in words {
+        let needed = if current.is_empty() {
+            word.len()
+        } else {
+            current.len() + 1 + word.len()
+        };
+        if needed > max_length {
+            lines.push(current);
+            current = word;
+        } else if current.is_empty() {
+            current = word;
+        } else {
+            current.push(' ');
+            current.push_str(&word);
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let canvas = Canvas::new(10, 20);
+        for y in 0..20 {
+            for x in 0..10 {
+                assert_eq!(canvas.pixel_at(x, y), RGB::new(0.0, 0.0, 0.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_pixel() {
+        let mut canvas = Canvas::new(10, 20);
+        let red = RGB::new(1.0, 0.0, 0.0);
+        canvas.write_pixel(2, 3, red);
+        assert_eq!(canvas.pixel_at(2, 3), red);
+    }
+
+    #[test]
+    fn test_to_ppm_header() {
+        let canvas = Canvas::new(5, 3);
+        let ppm = canvas.to_ppm();
+        let header: Vec<&str> = ppm.lines().take(3).collect();
+        assert_eq!(header, vec!["P3", "5 3", "255"]);
+    }
+
+    #[test]
+    fn test_to_ppm_pixel_data() {
+        let mut canvas = Canvas::new(5, 3);
+        canvas.write_pixel(0, 0, RGB::new(1.5, 0.0, 0.0));
+        canvas.write_pixel(2, 1, RGB::new(0.0, 0.5, 0.0));
+        canvas.write_pixel(4, 2, RGB::new(-0.5, 0.0, 1.0));
+        let ppm = canvas.to_ppm();
+        let lines: Vec<&str> = ppm.lines().skip(3).take(3).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "255 0 0 0 0 0 0 0 0 0 0 0 0 0 0",
+                "0 0 0 0 0 0 0 128 0 0 0 0 0 0 0",
+                "0 0 0 0 0 0 0 0 0 0 0 0 0 0 255",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_to_ppm_splits_long_lines() {
+        let mut canvas = Canvas::new(10, 2);
+        let color = RGB::new(1.0, 0.8, 0.6);
+        for y in 0..2 {
+            for x in 0..10 {
+                canvas.write_pixel(x, y, color);
+            }
+        }
+        let ppm = canvas.to_ppm();
+        let lines: Vec<&str> = ppm.lines().skip(3).take(4).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+                "153 255 204 153 255 204 153 255 204 153 255 204 153",
+                "255 204 153 255 204 153 255 204 153 255 204 153 255 204 153 255 204",
+                "153 255 204 153 255 204 153 255 204 153 255 204 153",
+            ]
+        );
+        for line in ppm.lines() {
+            assert!(line.len() <= 70);
+        }
+    }
+
+    #[test]
+    fn test_to_ppm_ends_with_newline() {
+        let canvas = Canvas::new(5, 3);
+        assert!(canvas.to_ppm().ends_with('\n'));
+    }
+}
@@ -1,7 +1,7 @@
 use std::ops::{Add, Mul, Sub};
 
 /// Representation of a color.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct RGB {
     /// red component
     r: f64,
@@ -29,6 +29,21 @@ impl RGB {
     pub fn new(r: f64, g: f64, b: f64) -> RGB {
         RGB { r, g, b }
     }
+
+    /// Returns this color as `(r, g, b)` bytes, clamping each component to
+    /// `[0, 1]` and scaling by 255, rounding to the nearest integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::color::RGB;
+    /// let color: RGB = RGB::new(1.5, 0.0, -0.5);
+    /// assert_eq!(color.to_bytes(), (255, 0, 0));
+    /// ```
+    pub fn to_bytes(&self) -> (u8, u8, u8) {
+        let scale = |component: f64| (component.clamp(0.0, 1.0) * 255.0).round() as u8;
+        (scale(self.r), scale(self.g), scale(self.b))
+    }
 }
 
 impl Add<RGB> for RGB {
@@ -0,0 +1,214 @@
+use crate::color::RGB;
+use crate::math::{Point, Vector};
+
+/// The surface properties used by the Phong reflection model.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Material {
+    /// the surface color
+    pub color: RGB,
+    /// the ambient reflection, usually between 0 and 1
+    pub ambient: f64,
+    /// the diffuse reflection, usually between 0 and 1
+    pub diffuse: f64,
+    /// the specular reflection, usually between 0 and 1
+    pub specular: f64,
+    /// how tight the specular highlight is
+    pub shininess: f64,
+}
+
+impl Material {
+    /// Returns a new material.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` the surface color
+    /// * `ambient` the ambient reflection, usually between 0 and 1
+    /// * `diffuse` the diffuse reflection, usually between 0 and 1
+    /// * `specular` the specular reflection, usually between 0 and 1
+    /// * `shininess` how tight the specular highlight is
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::color::RGB;
+    /// # use noray::lighting::Material;
+    /// let material: Material = Material::new(RGB::new(1.0, 1.0, 1.0), 0.1, 0.9, 0.9, 200.0);
+    /// ```
+    pub fn new(color: RGB, ambient: f64, diffuse: f64, specular: f64, shininess: f64) -> Material {
+        Material {
+            color,
+            ambient,
+            diffuse,
+            specular,
+            shininess,
+        }
+    }
+}
+
+impl Default for Material {
+    /// Returns the default material: white, with modest ambient, strong
+    /// diffuse and specular, and a tight highlight.
+    fn default() -> Material {
+        Material::new(RGB::new(1.0, 1.0, 1.0), 0.1, 0.9, 0.9, 200.0)
+    }
+}
+
+/// A point light source with no size, emitting `intensity` in all directions.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PointLight {
+    /// where the light is
+    pub position: Point,
+    /// the color and brightness of the light
+    pub intensity: RGB,
+}
+
+impl PointLight {
+    /// Returns a new point light.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` where the light is
+    /// * `intensity` the color and brightness of the light
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::color::RGB;
+    /// # use noray::math::Point;
+    /// # use noray::lighting::PointLight;
+    /// let light: PointLight = PointLight::new(Point::new(0.0, 0.0, 0.0), RGB::new(1.0, 1.0, 1.0));
+    /// ```
+    pub fn new(position: Point, intensity: RGB) -> PointLight {
+        PointLight {
+            position,
+            intensity,
+        }
+    }
+}
+
+/// Returns the color of a point on a surface, using the Phong reflection model.
+///
+/// # Arguments
+///
+/// * `material` the surface material at `point`
+/// * `light` the light illuminating `point`
+/// * `point` the point being shaded
+/// * `eye_v` the direction towards the eye
+/// * `normal_v` the surface normal at `point`
+///
+/// # Examples
+///
+/// ```
+/// # use noray::color::RGB;
+/// # use noray::math::{Point, Vector};
+/// # use noray::lighting::{lighting, Material, PointLight};
+/// let material = Material::default();
+/// let point = Point::new(0.0, 0.0, 0.0);
+/// let eye_v = Vector::new(0.0, 0.0, -1.0);
+/// let normal_v = Vector::new(0.0, 0.0, -1.0);
+/// let light = PointLight::new(Point::new(0.0, 0.0, -10.0), RGB::new(1.0, 1.0, 1.0));
+/// let color: RGB = lighting(&material, &light, &point, &eye_v, &normal_v);
+/// ```
+pub fn lighting(
+    material: &Material,
+    light: &PointLight,
+    point: &Point,
+    eye_v: &Vector,
+    normal_v: &Vector,
+) -> RGB {
+    let effective_color = material.color * light.intensity;
+    let light_v = (light.position - *point).normalize();
+    let ambient = effective_color * material.ambient;
+
+    let light_dot_normal = light_v.dot(normal_v);
+    let black = RGB::new(0.0, 0.0, 0.0);
+    let (diffuse, specular) = if light_dot_normal < 0.0 {
+        (black, black)
+    } else {
+        let diffuse = effective_color * material.diffuse * light_dot_normal;
+
+        let reflect_v = (-light_v).reflect(normal_v);
+        let reflect_dot_eye = reflect_v.dot(eye_v);
+        let specular = if reflect_dot_eye <= 0.0 {
+            black
+        } else {
+            light.intensity * material.specular * reflect_dot_eye.powf(material.shininess)
+        };
+
+        (diffuse, specular)
+    };
+
+    ambient + diffuse + specular
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lighting_eye_between_light_and_surface() {
+        let material = Material::default();
+        let point = Point::new(0.0, 0.0, 0.0);
+        let eye_v = Vector::new(0.0, 0.0, -1.0);
+        let normal_v = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), RGB::new(1.0, 1.0, 1.0));
+        assert_eq!(
+            lighting(&material, &light, &point, &eye_v, &normal_v),
+            RGB::new(1.9, 1.9, 1.9)
+        );
+    }
+
+    #[test]
+    fn test_lighting_eye_between_light_and_surface_offset_45_degrees() {
+        let material = Material::default();
+        let point = Point::new(0.0, 0.0, 0.0);
+        let eye_v = Vector::new(0.0, 2.0_f64.sqrt() / 2.0, -2.0_f64.sqrt() / 2.0);
+        let normal_v = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, -10.0), RGB::new(1.0, 1.0, 1.0));
+        assert_eq!(
+            lighting(&material, &light, &point, &eye_v, &normal_v),
+            RGB::new(1.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn test_lighting_eye_opposite_surface_light_offset_45_degrees() {
+        let material = Material::default();
+        let point = Point::new(0.0, 0.0, 0.0);
+        let eye_v = Vector::new(0.0, 0.0, -1.0);
+        let normal_v = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 10.0, -10.0), RGB::new(1.0, 1.0, 1.0));
+        let result = lighting(&material, &light, &point, &eye_v, &normal_v);
+        assert_eq!(
+            result,
+            RGB::new(0.7363961030678927, 0.7363961030678927, 0.7363961030678927)
+        );
+    }
+
+    #[test]
+    fn test_lighting_eye_in_path_of_reflection_vector() {
+        let material = Material::default();
+        let point = Point::new(0.0, 0.0, 0.0);
+        let eye_v = Vector::new(0.0, -2.0_f64.sqrt() / 2.0, -2.0_f64.sqrt() / 2.0);
+        let normal_v = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 10.0, -10.0), RGB::new(1.0, 1.0, 1.0));
+        let result = lighting(&material, &light, &point, &eye_v, &normal_v);
+        assert_eq!(
+            result,
+            RGB::new(1.6363961030678928, 1.6363961030678928, 1.6363961030678928)
+        );
+    }
+
+    #[test]
+    fn test_lighting_light_behind_surface() {
+        let material = Material::default();
+        let point = Point::new(0.0, 0.0, 0.0);
+        let eye_v = Vector::new(0.0, 0.0, -1.0);
+        let normal_v = Vector::new(0.0, 0.0, -1.0);
+        let light = PointLight::new(Point::new(0.0, 0.0, 10.0), RGB::new(1.0, 1.0, 1.0));
+        assert_eq!(
+            lighting(&material, &light, &point, &eye_v, &normal_v),
+            RGB::new(0.1, 0.1, 0.1)
+        );
+    }
+}
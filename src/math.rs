@@ -1,32 +1,86 @@
+use num_traits::{Float, NumCast};
+use std::fmt::Debug;
+use std::marker::PhantomData;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
-/// Representation of a point.
-#[derive(Debug, PartialEq)]
-pub struct Point {
+/// Marker trait distinguishing the flavor of a [`Tuple`], e.g. [`PointKind`] or [`VectorKind`].
+pub trait TupleKind: Copy + Clone + Debug {}
+
+/// Marks a [`Tuple`] as a point; see the [`Point`] alias.
+#[derive(Debug, Clone, Copy)]
+pub struct PointKind;
+impl TupleKind for PointKind {}
+
+/// Marks a [`Tuple`] as a vector; see the [`Vector`] alias.
+#[derive(Debug, Clone, Copy)]
+pub struct VectorKind;
+impl TupleKind for VectorKind {}
+
+/// Representation of a point or a vector, generic over both its scalar type
+/// and whether it is a point or a vector.
+///
+/// `K` is a zero-sized marker (see [`TupleKind`]) that keeps points and
+/// vectors distinct at compile time without duplicating their
+/// implementations.
+#[derive(Debug, Clone, Copy)]
+pub struct Tuple<T: Float + NumCast, K: TupleKind> {
     /// x coordinate
-    x: f64,
+    x: T,
     /// y coordinate
-    y: f64,
+    y: T,
     /// z coordinate
-    z: f64,
-    /// w coordinate; always 1.0
-    w: f64,
+    z: T,
+    /// w coordinate; 1 for points, 0 for vectors
+    w: T,
+    kind: PhantomData<K>,
 }
 
+/// Representation of a point.
+pub type Point<T = f64> = Tuple<T, PointKind>;
+
 /// Representation of a vector.
-#[derive(Debug, PartialEq)]
-pub struct Vector {
-    /// x coordinate
-    x: f64,
-    /// y coordinate
-    y: f64,
-    /// z coordinate
-    z: f64,
-    /// w coordinate; 1.0 for points, 0.0 for vectors
-    w: f64,
+pub type Vector<T = f64> = Tuple<T, VectorKind>;
+
+impl<T: Float + NumCast, K: TupleKind> Tuple<T, K> {
+    fn new_with_w(x: T, y: T, z: T, w: T) -> Tuple<T, K> {
+        Tuple {
+            x,
+            y,
+            z,
+            w,
+            kind: PhantomData,
+        }
+    }
+
+    /// Returns `true` if this tuple is equal to `other`, within `T::epsilon()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` the other tuple
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Vector;
+    /// let a: Vector = Vector::new(1.0, 2.0, 3.0);
+    /// let b: Vector = Vector::new(1.0, 2.0, 3.0);
+    /// assert!(a.approx_eq(&b));
+    /// ```
+    pub fn approx_eq(&self, other: &Tuple<T, K>) -> bool {
+        (self.x - other.x).abs() <= T::epsilon()
+            && (self.y - other.y).abs() <= T::epsilon()
+            && (self.z - other.z).abs() <= T::epsilon()
+            && (self.w - other.w).abs() <= T::epsilon()
+    }
 }
 
-impl Point {
+impl<T: Float + NumCast, K: TupleKind> PartialEq for Tuple<T, K> {
+    fn eq(&self, other: &Tuple<T, K>) -> bool {
+        self.x == other.x && self.y == other.y && self.z == other.z && self.w == other.w
+    }
+}
+
+impl<T: Float + NumCast> Tuple<T, PointKind> {
     /// Returns a new point.
     ///
     /// # Arguments
@@ -41,12 +95,12 @@ impl Point {
     /// # use noray::math::Point;
     /// let point: Point = Point::new(1.0, 2.0, 3.0);
     /// ```
-    pub fn new(x: f64, y: f64, z: f64) -> Point {
-        Point { x, y, z, w: 1.0 }
+    pub fn new(x: T, y: T, z: T) -> Point<T> {
+        Tuple::new_with_w(x, y, z, T::one())
     }
 }
 
-impl Vector {
+impl<T: Float + NumCast> Tuple<T, VectorKind> {
     /// Returns a new vector.
     ///
     /// # Arguments
@@ -61,8 +115,8 @@ impl Vector {
     /// # use noray::math::Vector;
     /// let vector: Vector = Vector::new(1.0, 2.0, 3.0);
     /// ```
-    pub fn new(x: f64, y: f64, z: f64) -> Vector {
-        Vector { x, y, z, w: 0.0 }
+    pub fn new(x: T, y: T, z: T) -> Vector<T> {
+        Tuple::new_with_w(x, y, z, T::zero())
     }
 
     /// Returns the magnitude of a vector.
@@ -74,7 +128,7 @@ impl Vector {
     /// let vector: Vector = Vector::new(1.0, 2.0, 3.0);
     /// let magnitude: f64 = vector.magnitude();
     /// ```
-    pub fn magnitude(&self) -> f64 {
+    pub fn magnitude(&self) -> T {
         (self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w).sqrt()
     }
 
@@ -87,7 +141,7 @@ impl Vector {
     /// let vector = Vector::new(1.0, 2.0, 3.0);
     /// let normalized: Vector = vector.normalize();
     /// ```
-    pub fn normalize(&self) -> Vector {
+    pub fn normalize(&self) -> Vector<T> {
         self / self.magnitude()
     }
 
@@ -105,7 +159,7 @@ impl Vector {
     /// let vector2: Vector = Vector::new(7.0, 8.0, 9.0);
     /// let dot_product: f64 = vector1.dot(&vector2);
     /// ```
-    pub fn dot(&self, rhs: &Vector) -> f64 {
+    pub fn dot(&self, rhs: &Vector<T>) -> T {
         self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
     }
 
@@ -123,17 +177,72 @@ impl Vector {
     /// let vector2: Vector = Vector::new(7.0, 8.0, 9.0);
     /// let cross_product: Vector = vector1.cross(&vector2);
     /// ```
-    pub fn cross(&self, rhs: &Vector) -> Vector {
+    pub fn cross(&self, rhs: &Vector<T>) -> Vector<T> {
         Vector::new(
             self.y * rhs.z - self.z * rhs.y,
             self.z * rhs.x - self.x * rhs.z,
             self.x * rhs.y - self.y * rhs.x,
         )
     }
+
+    /// Returns this vector reflected around `normal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` the normal to reflect around
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Vector;
+    /// let vector = Vector::new(1.0, -1.0, 0.0);
+    /// let normal = Vector::new(0.0, 1.0, 0.0);
+    /// let reflected: Vector = vector.reflect(&normal);
+    /// ```
+    pub fn reflect(&self, normal: &Vector<T>) -> Vector<T> {
+        let two = T::from(2.0).unwrap();
+        *self - normal * (two * self.dot(normal))
+    }
+
+    /// Returns the projection of this vector onto `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` the vector being projected onto
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Vector;
+    /// let vector = Vector::new(1.0, 1.0, 0.0);
+    /// let other = Vector::new(1.0, 0.0, 0.0);
+    /// let projected: Vector = vector.project_on(&other);
+    /// ```
+    pub fn project_on(&self, other: &Vector<T>) -> Vector<T> {
+        other * (self.dot(other) / other.dot(other))
+    }
+
+    /// Returns the angle, in radians, between this vector and `other`.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` the other vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Vector;
+    /// let vector = Vector::new(1.0, 0.0, 0.0);
+    /// let other = Vector::new(0.0, 1.0, 0.0);
+    /// let angle: f64 = vector.angle_between(&other);
+    /// ```
+    pub fn angle_between(&self, other: &Vector<T>) -> T {
+        self.normalize().dot(&other.normalize()).acos()
+    }
 }
 
-impl Add<Vector> for Vector {
-    type Output = Vector;
+impl<T: Float + NumCast> Add<Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
 
     /// Returns the sum of two vectors.
     ///
@@ -149,13 +258,13 @@ impl Add<Vector> for Vector {
     /// let tetrad2: Vector = Vector::new(9.0, 8.0, 7.0);
     /// let sum: Vector = tetrad1 + tetrad2;
     /// ```
-    fn add(self, rhs: Vector) -> Vector {
+    fn add(self, rhs: Vector<T>) -> Vector<T> {
         Vector::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
     }
 }
 
-impl Add<Vector> for Point {
-    type Output = Point;
+impl<T: Float + NumCast> Add<Vector<T>> for Point<T> {
+    type Output = Point<T>;
 
     /// Returns the sum of a point and a vector.
     ///
@@ -171,13 +280,13 @@ impl Add<Vector> for Point {
     /// let vector: Vector = Vector::new(9.0, 8.0, 7.0);
     /// let sum: Point = point + vector;
     /// ```
-    fn add(self, rhs: Vector) -> Point {
+    fn add(self, rhs: Vector<T>) -> Point<T> {
         Point::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
     }
 }
 
-impl Div<f64> for &Vector {
-    type Output = Vector;
+impl<T: Float + NumCast> Div<T> for &Vector<T> {
+    type Output = Vector<T>;
 
     /// Returns a new tetrad scaled by the inverse of the factor.
     ///
@@ -192,13 +301,13 @@ impl Div<f64> for &Vector {
     /// let vector: Vector = Vector::new(1.0, 2.0, 3.0);
     /// let scaled_vector: Vector = &vector / 5.0;
     /// ```
-    fn div(self, rhs: f64) -> Vector {
+    fn div(self, rhs: T) -> Vector<T> {
         Vector::new(self.x / rhs, self.y / rhs, self.z / rhs)
     }
 }
 
-impl Mul<f64> for &Vector {
-    type Output = Vector;
+impl<T: Float + NumCast> Mul<T> for &Vector<T> {
+    type Output = Vector<T>;
 
     /// Returns a new tetrad scale by the factor.
     ///
@@ -213,13 +322,13 @@ impl Mul<f64> for &Vector {
     /// let vector: Vector = Vector::new(1.0, 2.0, 3.0);
     /// let scaled_vector: Vector = &vector * 5.0;
     /// ```
-    fn mul(self, rhs: f64) -> Vector {
+    fn mul(self, rhs: T) -> Vector<T> {
         Vector::new(self.x * rhs, self.y * rhs, self.z * rhs)
     }
 }
 
-impl Neg for Vector {
-    type Output = Vector;
+impl<T: Float + NumCast> Neg for Vector<T> {
+    type Output = Vector<T>;
 
     /// Returns the negation of a vector.
     ///
@@ -230,13 +339,13 @@ impl Neg for Vector {
     /// let vector: Vector = Vector::new(1.0, 2.0, 3.0);
     /// let negation: Vector = -vector;
     /// ```
-    fn neg(self) -> Vector {
+    fn neg(self) -> Vector<T> {
         Vector::new(-self.x, -self.y, -self.z)
     }
 }
 
-impl Sub<Point> for Point {
-    type Output = Vector;
+impl<T: Float + NumCast> Sub<Point<T>> for Point<T> {
+    type Output = Vector<T>;
 
     /// Returns the vector representing the difference between two points.
     ///
@@ -252,13 +361,13 @@ impl Sub<Point> for Point {
     /// let point2: Point = Point::new(9.0, 8.0, 7.0);
     /// let difference: Vector = point1 - point2;
     /// ```
-    fn sub(self, rhs: Point) -> Vector {
+    fn sub(self, rhs: Point<T>) -> Vector<T> {
         Vector::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
     }
 }
 
-impl Sub<Vector> for Point {
-    type Output = Point;
+impl<T: Float + NumCast> Sub<Vector<T>> for Point<T> {
+    type Output = Point<T>;
 
     /// Returns the vector representing the difference between two points.
     ///
@@ -274,13 +383,13 @@ impl Sub<Vector> for Point {
     /// let vector: Vector = Vector::new(9.0, 8.0, 7.0);
     /// let difference: Point = point - vector;
     /// ```
-    fn sub(self, rhs: Vector) -> Point {
+    fn sub(self, rhs: Vector<T>) -> Point<T> {
         Point::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
     }
 }
 
-impl Sub<Vector> for Vector {
-    type Output = Vector;
+impl<T: Float + NumCast> Sub<Vector<T>> for Vector<T> {
+    type Output = Vector<T>;
 
     /// Returns the vector representing the difference between two points.
     ///
@@ -296,11 +405,462 @@ impl Sub<Vector> for Vector {
     /// let vector2: Vector = Vector::new(9.0, 8.0, 7.0);
     /// let difference: Vector = vector1 - vector2;
     /// ```
-    fn sub(self, rhs: Vector) -> Vector {
+    fn sub(self, rhs: Vector<T>) -> Vector<T> {
         Vector::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
     }
 }
 
+/// Representation of a 4x4 matrix, used to transform points and vectors.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Matrix {
+    /// rows of the matrix, in row-major order
+    data: [[f64; 4]; 4],
+}
+
+impl Matrix {
+    /// Returns a new matrix from the given rows.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` the rows of the matrix, in row-major order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let matrix: Matrix = Matrix::new([
+    ///     [1.0, 2.0, 3.0, 4.0],
+    ///     [5.0, 6.0, 7.0, 8.0],
+    ///     [9.0, 8.0, 7.0, 6.0],
+    ///     [5.0, 4.0, 3.0, 2.0],
+    /// ]);
+    /// ```
+    pub fn new(data: [[f64; 4]; 4]) -> Matrix {
+        Matrix { data }
+    }
+
+    /// Returns the 4x4 identity matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let identity: Matrix = Matrix::identity();
+    /// ```
+    pub fn identity() -> Matrix {
+        Matrix::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a matrix that translates by `(x, y, z)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` the x translation
+    /// * `y` the y translation
+    /// * `z` the z translation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let translation: Matrix = Matrix::translation(5.0, -3.0, 2.0);
+    /// ```
+    pub fn translation(x: f64, y: f64, z: f64) -> Matrix {
+        Matrix::new([
+            [1.0, 0.0, 0.0, x],
+            [0.0, 1.0, 0.0, y],
+            [0.0, 0.0, 1.0, z],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a matrix that scales by `(x, y, z)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` the x scale factor
+    /// * `y` the y scale factor
+    /// * `z` the z scale factor
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let scaling: Matrix = Matrix::scaling(2.0, 3.0, 4.0);
+    /// ```
+    pub fn scaling(x: f64, y: f64, z: f64) -> Matrix {
+        Matrix::new([
+            [x, 0.0, 0.0, 0.0],
+            [0.0, y, 0.0, 0.0],
+            [0.0, 0.0, z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a matrix that rotates around the x axis by `radians`.
+    ///
+    /// # Arguments
+    ///
+    /// * `radians` the angle of rotation, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let rotation: Matrix = Matrix::rotation_x(std::f64::consts::PI / 2.0);
+    /// ```
+    pub fn rotation_x(radians: f64) -> Matrix {
+        let (sin, cos) = radians.sin_cos();
+        Matrix::new([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, cos, -sin, 0.0],
+            [0.0, sin, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a matrix that rotates around the y axis by `radians`.
+    ///
+    /// # Arguments
+    ///
+    /// * `radians` the angle of rotation, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let rotation: Matrix = Matrix::rotation_y(std::f64::consts::PI / 2.0);
+    /// ```
+    pub fn rotation_y(radians: f64) -> Matrix {
+        let (sin, cos) = radians.sin_cos();
+        Matrix::new([
+            [cos, 0.0, sin, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [-sin, 0.0, cos, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a matrix that rotates around the z axis by `radians`.
+    ///
+    /// # Arguments
+    ///
+    /// * `radians` the angle of rotation, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let rotation: Matrix = Matrix::rotation_z(std::f64::consts::PI / 2.0);
+    /// ```
+    pub fn rotation_z(radians: f64) -> Matrix {
+        let (sin, cos) = radians.sin_cos();
+        Matrix::new([
+            [cos, -sin, 0.0, 0.0],
+            [sin, cos, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a matrix that shears each component in proportion to the other two.
+    ///
+    /// # Arguments
+    ///
+    /// * `xy` the amount x is moved in proportion to y
+    /// * `xz` the amount x is moved in proportion to z
+    /// * `yx` the amount y is moved in proportion to x
+    /// * `yz` the amount y is moved in proportion to z
+    /// * `zx` the amount z is moved in proportion to x
+    /// * `zy` the amount z is moved in proportion to y
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let shearing: Matrix = Matrix::shearing(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn shearing(xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Matrix {
+        Matrix::new([
+            [1.0, xy, xz, 0.0],
+            [yx, 1.0, yz, 0.0],
+            [zx, zy, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+    }
+
+    /// Returns a new matrix that applies this translation after any
+    /// transform already on `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` the x translation
+    /// * `y` the y translation
+    /// * `z` the z translation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let transform: Matrix = Matrix::identity().translate(5.0, -3.0, 2.0);
+    /// ```
+    pub fn translate(self, x: f64, y: f64, z: f64) -> Matrix {
+        &Matrix::translation(x, y, z) * &self
+    }
+
+    /// Returns a new matrix that applies this scaling after any transform
+    /// already on `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` the x scale factor
+    /// * `y` the y scale factor
+    /// * `z` the z scale factor
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let transform: Matrix = Matrix::identity().scale(2.0, 3.0, 4.0);
+    /// ```
+    pub fn scale(self, x: f64, y: f64, z: f64) -> Matrix {
+        &Matrix::scaling(x, y, z) * &self
+    }
+
+    /// Returns a new matrix that applies this rotation around the x axis
+    /// after any transform already on `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `radians` the angle of rotation, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let transform: Matrix = Matrix::identity().rotate_x(std::f64::consts::PI / 2.0);
+    /// ```
+    pub fn rotate_x(self, radians: f64) -> Matrix {
+        &Matrix::rotation_x(radians) * &self
+    }
+
+    /// Returns a new matrix that applies this rotation around the y axis
+    /// after any transform already on `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `radians` the angle of rotation, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let transform: Matrix = Matrix::identity().rotate_y(std::f64::consts::PI / 2.0);
+    /// ```
+    pub fn rotate_y(self, radians: f64) -> Matrix {
+        &Matrix::rotation_y(radians) * &self
+    }
+
+    /// Returns a new matrix that applies this rotation around the z axis
+    /// after any transform already on `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `radians` the angle of rotation, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let transform: Matrix = Matrix::identity().rotate_z(std::f64::consts::PI / 2.0);
+    /// ```
+    pub fn rotate_z(self, radians: f64) -> Matrix {
+        &Matrix::rotation_z(radians) * &self
+    }
+
+    /// Returns a new matrix that applies this shearing after any transform
+    /// already on `self`.
+    ///
+    /// # Arguments
+    ///
+    /// * `xy` the amount x is moved in proportion to y
+    /// * `xz` the amount x is moved in proportion to z
+    /// * `yx` the amount y is moved in proportion to x
+    /// * `yz` the amount y is moved in proportion to z
+    /// * `zx` the amount z is moved in proportion to x
+    /// * `zy` the amount z is moved in proportion to y
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let transform: Matrix = Matrix::identity().shear(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub fn shear(self, xy: f64, xz: f64, yx: f64, yz: f64, zx: f64, zy: f64) -> Matrix {
+        &Matrix::shearing(xy, xz, yx, yz, zx, zy) * &self
+    }
+
+    /// Returns the transpose of this matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let transposed: Matrix = Matrix::identity().transpose();
+    /// ```
+    pub fn transpose(&self) -> Matrix {
+        let mut data = [[0.0; 4]; 4];
+        for (row, cols) in data.iter_mut().enumerate() {
+            for (col, cell) in cols.iter_mut().enumerate() {
+                *cell = self.data[col][row];
+            }
+        }
+        Matrix::new(data)
+    }
+
+    /// Returns the inverse of this matrix, or `None` if it has no inverse.
+    ///
+    /// Computed via Gauss-Jordan elimination: the matrix is augmented with the
+    /// identity matrix and brought to reduced row-echelon form, leaving the inverse in
+    /// the augmented half.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let inverse = Matrix::translation(5.0, -3.0, 2.0).inverse();
+    /// assert!(inverse.is_some());
+    /// ```
+    pub fn inverse(&self) -> Option<Matrix> {
+        let mut augmented = [[0.0; 8]; 4];
+        for (row, source) in self.data.iter().enumerate() {
+            augmented[row][..4].copy_from_slice(source);
+            augmented[row][4 + row] = 1.0;
+        }
+
+        for pivot in 0..4 {
+            let best = (pivot..4)
+                .max_by(|&a, &b| {
+                    augmented[a][pivot]
+                        .abs()
+                        .total_cmp(&augmented[b][pivot].abs())
+                })
+                .unwrap();
+            if augmented[best][pivot].abs() < f64::EPSILON {
+                return None;
+            }
+            augmented.swap(pivot, best);
+
+            let divisor = augmented[pivot][pivot];
+            for value in augmented[pivot].iter_mut() {
+                *value /= divisor;
+            }
+
+            for row in 0..4 {
+                if row == pivot {
+                    continue;
+                }
+                let factor = augmented[row][pivot];
+                let pivot_row = augmented[pivot];
+                for (cell, pivot_cell) in augmented[row].iter_mut().zip(pivot_row.iter()) {
+                    *cell -= factor * pivot_cell;
+                }
+            }
+        }
+
+        let mut data = [[0.0; 4]; 4];
+        for (row, cols) in data.iter_mut().enumerate() {
+            for (col, cell) in cols.iter_mut().enumerate() {
+                *cell = augmented[row][4 + col];
+            }
+        }
+        Some(Matrix::new(data))
+    }
+}
+
+impl Mul<&Matrix> for &Matrix {
+    type Output = Matrix;
+
+    /// Returns the product of two matrices.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` the other matrix
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// let a: Matrix = Matrix::identity();
+    /// let b: Matrix = Matrix::scaling(2.0, 2.0, 2.0);
+    /// let product: Matrix = &a * &b;
+    /// ```
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        let mut data = [[0.0; 4]; 4];
+        for (row, cols) in data.iter_mut().enumerate() {
+            for (col, cell) in cols.iter_mut().enumerate() {
+                *cell = (0..4).map(|k| self.data[row][k] * rhs.data[k][col]).sum();
+            }
+        }
+        Matrix::new(data)
+    }
+}
+
+impl Mul<&Point> for &Matrix {
+    type Output = Point;
+
+    /// Returns the point produced by transforming `rhs` by this matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` the point being transformed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use noray::math::{Matrix, Point};
+    /// let transform: Matrix = Matrix::translation(5.0, -3.0, 2.0);
+    /// let point: Point = Point::new(-3.0, 4.0, 5.0);
+    /// let moved: Point = &transform * &point;
+    /// ```
+    fn mul(self, rhs: &Point) -> Point {
+        let components = [rhs.x, rhs.y, rhs.z, rhs.w];
+        let row = |i: usize| -> f64 { (0..4).map(|k| self.data[i][k] * components[k]).sum() };
+        Point::new(row(0), row(1), row(2))
+    }
+}
+
+impl Mul<&Vector> for &Matrix {
+    type Output = Vector;
+
+    /// Returns the vector produced by transforming `rhs` by this matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `rhs` the vector being transformed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use noray::math::{Matrix, Vector};
+    /// let transform: Matrix = Matrix::scaling(2.0, 3.0, 4.0);
+    /// let vector: Vector = Vector::new(-4.0, 6.0, 8.0);
+    /// let scaled: Vector = &transform * &vector;
+    /// ```
+    fn mul(self, rhs: &Vector) -> Vector {
+        let components = [rhs.x, rhs.y, rhs.z, rhs.w];
+        let row = |i: usize| -> f64 { (0..4).map(|k| self.data[i][k] * components[k]).sum() };
+        Vector::new(row(0), row(1), row(2))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -344,6 +904,41 @@ mod tests {
         assert_eq!(vector2.cross(&vector1), Vector::new(1.0, -2.0, 1.0));
     }
 
+    #[test]
+    fn test_reflect_at_45_degrees() {
+        let vector = Vector::new(1.0, -1.0, 0.0);
+        let normal = Vector::new(0.0, 1.0, 0.0);
+        assert_eq!(vector.reflect(&normal), Vector::new(1.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_reflect_off_slanted_surface() {
+        let vector = Vector::new(0.0, -1.0, 0.0);
+        let normal = Vector::new(2.0_f64.sqrt() / 2.0, 2.0_f64.sqrt() / 2.0, 0.0);
+        // The sqrt()-based normal leaves reflect() a float epsilon away from
+        // the exact (1.0, 0.0, 0.0), so compare with tolerance.
+        assert!(vector
+            .reflect(&normal)
+            .approx_eq(&Vector::new(1.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_project_on() {
+        let vector = Vector::new(1.0, 1.0, 0.0);
+        let other = Vector::new(1.0, 0.0, 0.0);
+        assert_eq!(vector.project_on(&other), Vector::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn test_angle_between() {
+        let vector = Vector::new(1.0, 0.0, 0.0);
+        let other = Vector::new(0.0, 1.0, 0.0);
+        assert_eq!(vector.angle_between(&other), std::f64::consts::FRAC_PI_2);
+
+        let vector = Vector::new(1.0, 0.0, 0.0);
+        assert_eq!(vector.angle_between(&vector), 0.0);
+    }
+
     #[test]
     fn test_add_point_and_vector() {
         let point = Point::new(3.0, -2.0, 5.0);
@@ -396,4 +991,148 @@ mod tests {
         let vector2 = Vector::new(-2.0, 3.0, 1.0);
         assert_eq!(vector1 - vector2, Vector::new(5.0, -5.0, 4.0));
     }
+
+    #[test]
+    fn test_approx_eq_tolerates_epsilon_sized_differences() {
+        let a = Vector::new(1.0, 2.0, 3.0);
+        let b = Vector::new(1.0 + f64::EPSILON, 2.0, 3.0);
+        assert!(a.approx_eq(&b));
+    }
+
+    #[test]
+    fn test_f32_precision() {
+        let vector1: Vector<f32> = Vector::new(1.0, 0.0, 0.0);
+        let vector2: Vector<f32> = Vector::new(0.0, 1.0, 0.0);
+        assert_eq!(vector1.cross(&vector2), Vector::new(0.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn test_matrix_mul() {
+        let a = Matrix::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 8.0, 7.0, 6.0],
+            [5.0, 4.0, 3.0, 2.0],
+        ]);
+        let b = Matrix::new([
+            [-2.0, 1.0, 2.0, 3.0],
+            [3.0, 2.0, 1.0, -1.0],
+            [4.0, 3.0, 6.0, 5.0],
+            [1.0, 2.0, 7.0, 8.0],
+        ]);
+        assert_eq!(
+            &a * &b,
+            Matrix::new([
+                [20.0, 22.0, 50.0, 48.0],
+                [44.0, 54.0, 114.0, 108.0],
+                [40.0, 58.0, 110.0, 102.0],
+                [16.0, 26.0, 46.0, 42.0],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_matrix_mul_identity() {
+        let a = Matrix::new([
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 8.0, 7.0, 6.0],
+            [5.0, 4.0, 3.0, 2.0],
+        ]);
+        assert_eq!(&a * &Matrix::identity(), a);
+    }
+
+    #[test]
+    fn test_matrix_mul_point() {
+        let transform = Matrix::translation(5.0, -3.0, 2.0);
+        let point = Point::new(-3.0, 4.0, 5.0);
+        assert_eq!(&transform * &point, Point::new(2.0, 1.0, 7.0));
+    }
+
+    #[test]
+    fn test_matrix_mul_vector_unaffected_by_translation() {
+        let transform = Matrix::translation(5.0, -3.0, 2.0);
+        let vector = Vector::new(-3.0, 4.0, 5.0);
+        assert_eq!(&transform * &vector, vector);
+    }
+
+    #[test]
+    fn test_matrix_scaling() {
+        let transform = Matrix::scaling(2.0, 3.0, 4.0);
+        let point = Point::new(-4.0, 6.0, 8.0);
+        assert_eq!(&transform * &point, Point::new(-8.0, 18.0, 32.0));
+    }
+
+    #[test]
+    fn test_matrix_rotation_x() {
+        let half_quarter = Matrix::rotation_x(std::f64::consts::PI / 4.0);
+        let full_quarter = Matrix::rotation_x(std::f64::consts::PI / 2.0);
+        let point = Point::new(0.0, 1.0, 0.0);
+        // sin_cos() and sqrt() can land a ULP apart, so compare with tolerance.
+        let expected = Point::new(0.0, 2.0_f64.sqrt() / 2.0, 2.0_f64.sqrt() / 2.0);
+        assert!((&half_quarter * &point).approx_eq(&expected));
+        // sin_cos() at pi/2 leaves a ULP-sized residue instead of an exact 0.0.
+        assert!((&full_quarter * &point).approx_eq(&Point::new(0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_matrix_shearing() {
+        let transform = Matrix::shearing(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        let point = Point::new(2.0, 3.0, 4.0);
+        assert_eq!(&transform * &point, Point::new(5.0, 3.0, 4.0));
+    }
+
+    #[test]
+    fn test_matrix_transpose() {
+        let a = Matrix::new([
+            [0.0, 9.0, 3.0, 0.0],
+            [9.0, 8.0, 0.0, 8.0],
+            [1.0, 8.0, 5.0, 3.0],
+            [0.0, 0.0, 5.0, 8.0],
+        ]);
+        assert_eq!(
+            a.transpose(),
+            Matrix::new([
+                [0.0, 9.0, 1.0, 0.0],
+                [9.0, 8.0, 8.0, 0.0],
+                [3.0, 0.0, 5.0, 5.0],
+                [0.0, 8.0, 3.0, 8.0],
+            ])
+        );
+    }
+
+    #[test]
+    fn test_matrix_inverse() {
+        let a = Matrix::translation(5.0, -3.0, 2.0);
+        let inverse = a.inverse().unwrap();
+        let point = Point::new(-3.0, 4.0, 5.0);
+        assert_eq!(&a * &point, Point::new(2.0, 1.0, 7.0));
+        assert_eq!(&inverse * &Point::new(2.0, 1.0, 7.0), point);
+    }
+
+    #[test]
+    fn test_matrix_inverse_of_identity_is_identity() {
+        assert_eq!(Matrix::identity().inverse().unwrap(), Matrix::identity());
+    }
+
+    #[test]
+    fn test_matrix_with_no_inverse_returns_none() {
+        let a = Matrix::new([
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ]);
+        assert!(a.inverse().is_none());
+    }
+
+    #[test]
+    fn test_matrix_chained_transformations() {
+        let transform = Matrix::identity()
+            .rotate_x(std::f64::consts::PI / 2.0)
+            .scale(5.0, 5.0, 5.0)
+            .translate(10.0, 5.0, 7.0);
+        let point = Point::new(1.0, 0.0, 1.0);
+        assert_eq!(&transform * &point, Point::new(15.0, 0.0, 7.0));
+    }
 }
@@ -0,0 +1,335 @@
+use crate::math::{Matrix, Point, Vector};
+
+/// A ray cast from `origin` in `direction`.
+#[derive(Debug, PartialEq)]
+pub struct Ray {
+    /// the point the ray starts from
+    pub origin: Point,
+    /// the direction the ray travels
+    pub direction: Vector,
+}
+
+impl Ray {
+    /// Returns a new ray.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` the point the ray starts from
+    /// * `direction` the direction the ray travels
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::{Point, Vector};
+    /// # use noray::ray::Ray;
+    /// let ray: Ray = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(4.0, 5.0, 6.0));
+    /// ```
+    pub fn new(origin: Point, direction: Vector) -> Ray {
+        Ray { origin, direction }
+    }
+
+    /// Returns the point at distance `t` along the ray.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` the distance along the ray
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::{Point, Vector};
+    /// # use noray::ray::Ray;
+    /// let ray: Ray = Ray::new(Point::new(2.0, 3.0, 4.0), Vector::new(1.0, 0.0, 0.0));
+    /// let position: Point = ray.position(1.0);
+    /// ```
+    pub fn position(&self, t: f64) -> Point {
+        self.origin + &self.direction * t
+    }
+
+    /// Returns a new ray produced by transforming this ray by `matrix`.
+    ///
+    /// # Arguments
+    ///
+    /// * `matrix` the transformation to apply
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::{Matrix, Point, Vector};
+    /// # use noray::ray::Ray;
+    /// let ray: Ray = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+    /// let translated: Ray = ray.transform(&Matrix::translation(3.0, 4.0, 5.0));
+    /// ```
+    pub fn transform(&self, matrix: &Matrix) -> Ray {
+        Ray::new(matrix * &self.origin, matrix * &self.direction)
+    }
+}
+
+/// A unit sphere, centered at the origin unless given its own transformation.
+#[derive(Debug, PartialEq)]
+pub struct Sphere {
+    /// the transformation from object space to world space
+    transform: Matrix,
+}
+
+impl Sphere {
+    /// Returns a new unit sphere centered at the origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::ray::Sphere;
+    /// let sphere: Sphere = Sphere::new();
+    /// ```
+    pub fn new() -> Sphere {
+        Sphere {
+            transform: Matrix::identity(),
+        }
+    }
+
+    /// Returns a new sphere with `transform` applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` the transformation from object space to world space
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::Matrix;
+    /// # use noray::ray::Sphere;
+    /// let sphere: Sphere = Sphere::new().with_transform(Matrix::scaling(2.0, 2.0, 2.0));
+    /// ```
+    pub fn with_transform(self, transform: Matrix) -> Sphere {
+        Sphere { transform }
+    }
+
+    /// Returns the intersections of `ray` with this sphere, sorted ascending by `t`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ray` the ray being cast at the sphere
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::math::{Point, Vector};
+    /// # use noray::ray::{Ray, Sphere};
+    /// let sphere: Sphere = Sphere::new();
+    /// let ray: Ray = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+    /// let intersections = sphere.intersect(&ray);
+    /// assert_eq!(intersections.len(), 2);
+    /// ```
+    pub fn intersect(&self, ray: &Ray) -> Vec<Intersection<'_>> {
+        let inverse = match self.transform.inverse() {
+            Some(inverse) => inverse,
+            None => return Vec::new(),
+        };
+        let local_ray = ray.transform(&inverse);
+
+        let sphere_to_ray = local_ray.origin - Point::new(0.0, 0.0, 0.0);
+        let a = local_ray.direction.dot(&local_ray.direction);
+        let b = 2.0 * local_ray.direction.dot(&sphere_to_ray);
+        let c = sphere_to_ray.dot(&sphere_to_ray) - 1.0;
+        let discriminant = b * b - 4.0 * a * c;
+
+        if discriminant < 0.0 {
+            return Vec::new();
+        }
+
+        let sqrt_discriminant = discriminant.sqrt();
+        let t1 = (-b - sqrt_discriminant) / (2.0 * a);
+        let t2 = (-b + sqrt_discriminant) / (2.0 * a);
+        vec![Intersection::new(t1, self), Intersection::new(t2, self)]
+    }
+}
+
+impl Default for Sphere {
+    fn default() -> Sphere {
+        Sphere::new()
+    }
+}
+
+/// A single intersection of a ray with an object, at distance `t`.
+#[derive(Debug, PartialEq)]
+pub struct Intersection<'a> {
+    /// the distance along the ray at which the intersection occurs
+    pub t: f64,
+    /// the object that was hit
+    pub object: &'a Sphere,
+}
+
+impl<'a> Intersection<'a> {
+    /// Returns a new intersection.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` the distance along the ray at which the intersection occurs
+    /// * `object` the object that was hit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noray::ray::{Intersection, Sphere};
+    /// let sphere: Sphere = Sphere::new();
+    /// let intersection: Intersection = Intersection::new(3.5, &sphere);
+    /// ```
+    pub fn new(t: f64, object: &'a Sphere) -> Intersection<'a> {
+        Intersection { t, object }
+    }
+}
+
+/// Returns the visible intersection (the lowest non-negative `t`), if any.
+///
+/// # Arguments
+///
+/// * `intersections` the intersections to consider, in any order
+///
+/// # Examples
+///
+/// ```
+/// # use noray::ray::{hit, Intersection, Sphere};
+/// let sphere = Sphere::new();
+/// let intersections = vec![Intersection::new(-1.0, &sphere), Intersection::new(1.0, &sphere)];
+/// let visible = hit(&intersections).unwrap();
+/// assert_eq!(visible.t, 1.0);
+/// ```
+pub fn hit<'a, 'b>(intersections: &'b [Intersection<'a>]) -> Option<&'b Intersection<'a>> {
+    intersections
+        .iter()
+        .filter(|intersection| intersection.t >= 0.0)
+        .min_by(|a, b| a.t.total_cmp(&b.t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::{Point, Vector};
+
+    #[test]
+    fn test_position() {
+        let ray = Ray::new(Point::new(2.0, 3.0, 4.0), Vector::new(1.0, 0.0, 0.0));
+        assert_eq!(ray.position(0.0), Point::new(2.0, 3.0, 4.0));
+        assert_eq!(ray.position(1.0), Point::new(3.0, 3.0, 4.0));
+        assert_eq!(ray.position(-1.0), Point::new(1.0, 3.0, 4.0));
+        assert_eq!(ray.position(2.5), Point::new(4.5, 3.0, 4.0));
+    }
+
+    #[test]
+    fn test_transform_translation() {
+        let ray = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+        let transformed = ray.transform(&Matrix::translation(3.0, 4.0, 5.0));
+        assert_eq!(transformed.origin, Point::new(4.0, 6.0, 8.0));
+        assert_eq!(transformed.direction, Vector::new(0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn test_transform_scaling() {
+        let ray = Ray::new(Point::new(1.0, 2.0, 3.0), Vector::new(0.0, 1.0, 0.0));
+        let transformed = ray.transform(&Matrix::scaling(2.0, 3.0, 4.0));
+        assert_eq!(transformed.origin, Point::new(2.0, 6.0, 12.0));
+        assert_eq!(transformed.direction, Vector::new(0.0, 3.0, 0.0));
+    }
+
+    #[test]
+    fn test_intersect_two_points() {
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let sphere = Sphere::new();
+        let xs = sphere.intersect(&ray);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 4.0);
+        assert_eq!(xs[1].t, 6.0);
+    }
+
+    #[test]
+    fn test_intersect_tangent() {
+        let ray = Ray::new(Point::new(0.0, 1.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let sphere = Sphere::new();
+        let xs = sphere.intersect(&ray);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 5.0);
+        assert_eq!(xs[1].t, 5.0);
+    }
+
+    #[test]
+    fn test_intersect_misses() {
+        let ray = Ray::new(Point::new(0.0, 2.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let sphere = Sphere::new();
+        assert_eq!(sphere.intersect(&ray).len(), 0);
+    }
+
+    #[test]
+    fn test_intersect_from_inside() {
+        let ray = Ray::new(Point::new(0.0, 0.0, 0.0), Vector::new(0.0, 0.0, 1.0));
+        let sphere = Sphere::new();
+        let xs = sphere.intersect(&ray);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, -1.0);
+        assert_eq!(xs[1].t, 1.0);
+    }
+
+    #[test]
+    fn test_intersect_sphere_behind_ray() {
+        let ray = Ray::new(Point::new(0.0, 0.0, 5.0), Vector::new(0.0, 0.0, 1.0));
+        let sphere = Sphere::new();
+        let xs = sphere.intersect(&ray);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, -6.0);
+        assert_eq!(xs[1].t, -4.0);
+    }
+
+    #[test]
+    fn test_intersect_scaled_sphere() {
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let sphere = Sphere::new().with_transform(Matrix::scaling(2.0, 2.0, 2.0));
+        let xs = sphere.intersect(&ray);
+        assert_eq!(xs.len(), 2);
+        assert_eq!(xs[0].t, 3.0);
+        assert_eq!(xs[1].t, 7.0);
+    }
+
+    #[test]
+    fn test_intersect_translated_sphere_misses() {
+        let ray = Ray::new(Point::new(0.0, 0.0, -5.0), Vector::new(0.0, 0.0, 1.0));
+        let sphere = Sphere::new().with_transform(Matrix::translation(5.0, 0.0, 0.0));
+        assert_eq!(sphere.intersect(&ray).len(), 0);
+    }
+
+    #[test]
+    fn test_hit_all_positive() {
+        let sphere = Sphere::new();
+        let i1 = Intersection::new(1.0, &sphere);
+        let i2 = Intersection::new(2.0, &sphere);
+        let xs = vec![i1, i2];
+        assert_eq!(hit(&xs).unwrap().t, 1.0);
+    }
+
+    #[test]
+    fn test_hit_some_negative() {
+        let sphere = Sphere::new();
+        let i1 = Intersection::new(-1.0, &sphere);
+        let i2 = Intersection::new(1.0, &sphere);
+        let xs = vec![i1, i2];
+        assert_eq!(hit(&xs).unwrap().t, 1.0);
+    }
+
+    #[test]
+    fn test_hit_all_negative() {
+        let sphere = Sphere::new();
+        let i1 = Intersection::new(-2.0, &sphere);
+        let i2 = Intersection::new(-1.0, &sphere);
+        let xs = vec![i1, i2];
+        assert_eq!(hit(&xs), None);
+    }
+
+    #[test]
+    fn test_hit_lowest_nonnegative() {
+        let sphere = Sphere::new();
+        let i1 = Intersection::new(5.0, &sphere);
+        let i2 = Intersection::new(7.0, &sphere);
+        let i3 = Intersection::new(-3.0, &sphere);
+        let i4 = Intersection::new(2.0, &sphere);
+        let xs = vec![i1, i2, i3, i4];
+        assert_eq!(hit(&xs).unwrap().t, 2.0);
+    }
+}